@@ -0,0 +1,110 @@
+//! HMAC-SHA256 keyed hashing, built on the SHA-256 compression function.
+//! spec: RFC 2104 https://www.rfc-editor.org/rfc/rfc2104
+
+use crate::{HashEngine, SHA256};
+
+const BLOCK_SIZE: usize = 64;
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// incremental HMAC-SHA256, mirroring `HashEngine`'s `update`/`finalize`
+/// shape so large messages don't need to be buffered
+pub struct Hmac {
+    outer_key: [u8; BLOCK_SIZE],
+    inner: HashEngine,
+}
+
+impl Hmac {
+    pub fn new(key: &[u8]) -> Self {
+        let derived_key = Hmac::derive_key(key);
+
+        let mut outer_key = [0u8; BLOCK_SIZE];
+        let mut inner_key = [0u8; BLOCK_SIZE];
+        for i in 0..BLOCK_SIZE {
+            outer_key[i] = derived_key[i] ^ OPAD;
+            inner_key[i] = derived_key[i] ^ IPAD;
+        }
+
+        let mut inner = HashEngine::new();
+        inner.update(&inner_key);
+
+        Hmac { outer_key, inner }
+    }
+
+    /// RFC 2104 `K'`: hash the key down if it's longer than a block,
+    /// otherwise right-pad it with zeros to a full block
+    fn derive_key(key: &[u8]) -> [u8; BLOCK_SIZE] {
+        let mut block = [0u8; BLOCK_SIZE];
+        if key.len() > BLOCK_SIZE {
+            let hashed = SHA256::new().exec_bytes(key);
+            block[..hashed.len()].copy_from_slice(&hashed);
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+        block
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        let inner_digest = self.inner.finalize();
+
+        let mut outer = HashEngine::new();
+        outer.update(&self.outer_key);
+        outer.update(&inner_digest);
+        outer.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hmac_rfc4231_case_1() {
+        let key = [0x0bu8; 20];
+
+        let mut hmac = Hmac::new(&key);
+        hmac.update(b"Hi There");
+
+        assert_eq!(
+            hmac.finalize(),
+            hex_to_bytes("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+        );
+    }
+
+    #[test]
+    fn test_hmac_with_key_longer_than_block() {
+        let key: Vec<u8> = (0..100u16).map(|b| b as u8).collect();
+
+        let mut hmac = Hmac::new(&key);
+        hmac.update(b"msg");
+
+        assert_eq!(
+            hmac.finalize(),
+            hex_to_bytes("f85da02f25a44a117825adec49678dd31f98d263ba21680c07fd30c161cda4ec")
+        );
+    }
+
+    #[test]
+    fn test_hmac_fed_in_pieces() {
+        let mut whole = Hmac::new(b"key");
+        whole.update(b"The quick brown fox jumps over the lazy dog");
+
+        let mut piecemeal = Hmac::new(b"key");
+        piecemeal.update(b"The quick brown fox ");
+        piecemeal.update(b"jumps over the lazy dog");
+
+        assert_eq!(whole.finalize(), piecemeal.finalize());
+    }
+
+    fn hex_to_bytes(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for i in 0..32 {
+            bytes[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+}
@@ -0,0 +1,198 @@
+//! SHA-256 Merkle tree / content-addressing over arbitrary byte data.
+//!
+//! The input is split into fixed-size leaf chunks, each leaf is hashed with
+//! SHA-256, and sibling pairs are hashed together bottom-up (a lone odd node
+//! is promoted to the next level unchanged) until a single root remains.
+//! Roots (and any other node) can be rendered as a Base32 string so they can
+//! be used as human-shareable content addresses.
+
+use crate::SHA256;
+
+/// default leaf chunk size: 64 KiB
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// build a Merkle tree over `data` using the default chunk size and
+/// return its root
+pub fn merkle_root(data: &[u8]) -> [u8; 32] {
+    merkle_root_with_chunk_size(data, DEFAULT_CHUNK_SIZE)
+}
+
+/// build a Merkle tree over `data`, splitting it into `chunk_size`-byte
+/// leaves, and return its root
+pub fn merkle_root_with_chunk_size(data: &[u8], chunk_size: usize) -> [u8; 32] {
+    let mut level = leaf_hashes(data, chunk_size);
+
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+
+    level[0]
+}
+
+/// one level of a Merkle inclusion proof: either the current node was
+/// paired with a sibling, or it was the lone node at that level and was
+/// promoted to the next level unchanged (see `next_level`)
+#[derive(Copy, Clone)]
+pub enum ProofStep {
+    /// paired with `sibling`, which sits on the left iff `sibling_is_left`
+    Pair { sibling: [u8; 32], sibling_is_left: bool },
+    /// the lone node at this level, carried up unchanged
+    Promote,
+}
+
+/// recompute the root from a leaf and its proof path, and check it
+/// against `root` — the standard Merkle inclusion proof. each step
+/// carries its own pairing side (or says the node was promoted
+/// unchanged), so the walk doesn't need to infer level structure from a
+/// leaf index.
+pub fn verify_proof(leaf: &[u8], proof: &[ProofStep], root: &[u8; 32]) -> bool {
+    let mut hash = SHA256::new().exec_bytes(leaf);
+
+    for step in proof {
+        hash = match step {
+            ProofStep::Pair { sibling, sibling_is_left: true } => hash_pair(sibling, &hash),
+            ProofStep::Pair { sibling, sibling_is_left: false } => hash_pair(&hash, sibling),
+            ProofStep::Promote => hash,
+        };
+    }
+
+    hash == *root
+}
+
+/// render a node's 256-bit identifier as a Base32 string (RFC 4648
+/// alphabet, no padding), suitable as a human-shareable address
+pub fn node_id(node: &[u8; 32]) -> String {
+    let mut id = String::with_capacity((node.len() * 8 + 4) / 5);
+    let mut buffer: u32 = 0;
+    let mut bits_in_buffer = 0;
+
+    for &byte in node {
+        buffer = (buffer << 8) | byte as u32;
+        bits_in_buffer += 8;
+
+        while bits_in_buffer >= 5 {
+            bits_in_buffer -= 5;
+            let index = (buffer >> bits_in_buffer) & 0x1f;
+            id.push(BASE32_ALPHABET[index as usize] as char);
+        }
+    }
+
+    if bits_in_buffer > 0 {
+        let index = (buffer << (5 - bits_in_buffer)) & 0x1f;
+        id.push(BASE32_ALPHABET[index as usize] as char);
+    }
+
+    id
+}
+
+fn leaf_hashes(data: &[u8], chunk_size: usize) -> Vec<[u8; 32]> {
+    if data.is_empty() {
+        return vec![SHA256::new().exec_bytes(&[])];
+    }
+
+    data.chunks(chunk_size.max(1))
+        .map(|chunk| SHA256::new().exec_bytes(chunk))
+        .collect()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_pair(left, right),
+            [lone] => *lone,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut combined = Vec::with_capacity(64);
+    combined.extend_from_slice(left);
+    combined.extend_from_slice(right);
+    SHA256::new().exec_bytes(&combined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merkle_root_of_four_leaves() {
+        let root = merkle_root_with_chunk_size(b"abcdefgh", 2);
+
+        assert_eq!(
+            root,
+            hex_to_bytes("78dbd0dc4e4afecead1a92cc4212d62be278baeb2117b1f7304e0b432da846e5")
+        );
+    }
+
+    #[test]
+    fn test_node_id_uses_base32_alphabet_without_padding() {
+        let root = merkle_root(b"hello world");
+        let id = node_id(&root);
+
+        assert!(id.chars().all(|c| BASE32_ALPHABET.contains(&(c as u8))));
+        assert!(!id.contains('='));
+    }
+
+    #[test]
+    fn test_merkle_root_promotes_lone_odd_node() {
+        // three leaves: the third is a lone node and is promoted unchanged
+        // to the second level instead of being paired with itself
+        let leaves: Vec<[u8; 32]> = [b"ab", b"cd", b"ef"]
+            .iter()
+            .map(|c| SHA256::new().exec_bytes(*c))
+            .collect();
+        let expected = hash_pair(&hash_pair(&leaves[0], &leaves[1]), &leaves[2]);
+
+        assert_eq!(merkle_root_with_chunk_size(b"abcdef", 2), expected);
+    }
+
+    #[test]
+    fn test_verify_proof_for_first_leaf() {
+        let leaves: Vec<[u8; 32]> = [b"ab", b"cd", b"ef", b"gh"]
+            .iter()
+            .map(|c| SHA256::new().exec_bytes(*c))
+            .collect();
+        let pair1 = hash_pair(&leaves[2], &leaves[3]);
+        let root = merkle_root_with_chunk_size(b"abcdefgh", 2);
+        let proof = [
+            ProofStep::Pair { sibling: leaves[1], sibling_is_left: false },
+            ProofStep::Pair { sibling: pair1, sibling_is_left: false },
+        ];
+
+        assert!(verify_proof(b"ab", &proof, &root));
+        assert!(!verify_proof(b"ax", &proof, &root));
+    }
+
+    #[test]
+    fn test_verify_proof_for_promoted_lone_node() {
+        // three leaves: "ef" is the lone node at level 0 and is promoted
+        // unchanged, so its proof has no sibling for that level, only a
+        // `Promote` step, followed by pairing with the level-1 node
+        let leaves: Vec<[u8; 32]> = [b"ab", b"cd", b"ef"]
+            .iter()
+            .map(|c| SHA256::new().exec_bytes(*c))
+            .collect();
+        let pair01 = hash_pair(&leaves[0], &leaves[1]);
+        let root = hash_pair(&pair01, &leaves[2]);
+        let proof = [
+            ProofStep::Promote,
+            ProofStep::Pair { sibling: pair01, sibling_is_left: true },
+        ];
+
+        assert!(verify_proof(b"ef", &proof, &root));
+        assert!(!verify_proof(b"ex", &proof, &root));
+    }
+
+    fn hex_to_bytes(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for i in 0..32 {
+            bytes[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+}
@@ -0,0 +1,503 @@
+//! The SHA-256 hash algorithm.
+//! spec: https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf
+//! 6.2 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=23
+
+#[derive(Copy, Clone)]
+pub struct SHA256;
+impl SHA256 {
+    /*
+     *
+     * constant
+     *
+     */
+    const BLOCK_SIZE: usize = 64;
+    const DELIMITER: u32 = 0x80;
+
+    /// 4bytes after the decimal point of the cube root of 64 prime numbers from smallest to largest
+    /// https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=15
+    const K: [u32; 64] = [
+        0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4,
+        0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe,
+        0x9bdc06a7, 0xc19bf174, 0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f,
+        0x4a7484aa, 0x5cb0a9dc, 0x76f988da, 0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7,
+        0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967, 0x27b70a85, 0x2e1b2138, 0x4d2c6dfc,
+        0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85, 0xa2bfe8a1, 0xa81a664b,
+        0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xD6990624, 0xf40e3585, 0x106aa070, 0x19a4c116,
+        0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+        0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7,
+        0xc67178f2,
+    ];
+
+    /// 4bytes after the decimal point of the square root of 8 prime numbers from smallest to largest
+    /// 5.3.2  https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=18
+    const H: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    /*
+     *
+     * constructor
+     *
+     */
+    pub fn new() -> Self {
+        SHA256
+    }
+
+    /*
+     *
+     * get hashed string
+     *
+     */
+    pub fn exec(self, message: String) -> String {
+        hex(&self.exec_bytes(message.as_bytes()))
+    }
+
+    /// hash arbitrary binary input (not just UTF-8 `String`) and return
+    /// the raw 32-byte digest
+    pub fn exec_bytes(self, message: &[u8]) -> [u8; 32] {
+        let mut engine = HashEngine::new();
+        engine.update(message);
+        engine.finalize()
+    }
+
+    /// pre-prpcess
+    /// add padding and sizes to the message
+    /// 5. https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=17
+    pub fn add_padding(self, message: Vec<u8>) -> Vec<u8> {
+        const SIZE_BYTES: usize = 8;
+
+        let len = message.len();
+
+        let mut tmp: Vec<u8> = vec![0x00; SHA256::BLOCK_SIZE];
+        tmp[0] = SHA256::DELIMITER as u8;
+
+        // add padding
+        let mut padded = message.clone();
+        padded = if len % SHA256::BLOCK_SIZE < SHA256::BLOCK_SIZE - SIZE_BYTES {
+            vec![
+                padded,
+                tmp[0..(SHA256::BLOCK_SIZE - SIZE_BYTES - len % SHA256::BLOCK_SIZE)].to_vec(),
+            ]
+            .concat()
+        } else {
+            vec![
+                padded,
+                tmp[0..(SHA256::BLOCK_SIZE + SHA256::BLOCK_SIZE
+                    - SIZE_BYTES
+                    - len % SHA256::BLOCK_SIZE)]
+                    .to_vec(),
+            ]
+            .concat()
+        };
+
+        // add length, as a full 64-bit big-endian bit count (so messages up
+        // to 2^64 - 1 bits, not just 2^32 - 1, hash correctly)
+        let len_bits = (len as u64) * 8;
+        vec![padded, len_bits.to_be_bytes().to_vec()].concat()
+    }
+
+    /*
+     *
+     * bit opes funuctions
+     *
+     * https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+     *
+     */
+
+    /// 4.2 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    fn ch(self, x: u32, y: u32, z: u32) -> u32 {
+        (x & y) ^ (!x & z)
+    }
+    /// 4.3 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    fn maj(self, x: u32, y: u32, z: u32) -> u32 {
+        (x & y) ^ (x & z) ^ (y & z)
+    }
+
+    /// 4.4 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    #[allow(non_snake_case)]
+    fn SIGMA0(self, x: u32) -> u32 {
+        x.rotate_right(2) ^ x.rotate_right(13) ^ x.rotate_right(22)
+    }
+    /// 4.5 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    #[allow(non_snake_case)]
+    fn SIGMA1(self, x: u32) -> u32 {
+        x.rotate_right(6) ^ x.rotate_right(11) ^ x.rotate_right(25)
+    }
+    /// 4.6 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    fn sigma0(self, x: u32) -> u32 {
+        x.rotate_right(7) ^ x.rotate_right(18) ^ (x >> 3)
+    }
+    /// 4.7 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+    fn sigma1(self, x: u32) -> u32 {
+        x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
+    }
+}
+
+/// incremental SHA-256 hasher
+///
+/// unlike `SHA256::exec`, which needs the whole message up front, `HashEngine`
+/// accepts data through repeated `update` calls and only needs to hold one
+/// 64-byte block in memory at a time, so callers can hash files or streams
+/// larger than memory by feeding it chunks read from `io::Read`.
+#[derive(Copy, Clone)]
+pub struct HashEngine {
+    h: [u32; 8],
+    buffer: [u8; SHA256::BLOCK_SIZE],
+    buffer_len: usize,
+    length: usize,
+}
+
+impl HashEngine {
+    pub fn new() -> Self {
+        HashEngine {
+            h: SHA256::H,
+            buffer: [0; SHA256::BLOCK_SIZE],
+            buffer_len: 0,
+            length: 0,
+        }
+    }
+
+    /// feed more message bytes into the engine, compressing every full
+    /// 64-byte block as it accumulates and carrying the remainder over
+    pub fn update(&mut self, data: &[u8]) {
+        self.length += data.len();
+        let mut offset = 0;
+
+        if self.buffer_len > 0 {
+            let need = SHA256::BLOCK_SIZE - self.buffer_len;
+            let take = need.min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            offset = take;
+
+            if self.buffer_len < SHA256::BLOCK_SIZE {
+                // not enough data yet to complete the buffered block
+                return;
+            }
+
+            compress(&mut self.h, &self.buffer);
+            self.buffer_len = 0;
+        }
+
+        while offset + SHA256::BLOCK_SIZE <= data.len() {
+            let block: &[u8; SHA256::BLOCK_SIZE] = data[offset..offset + SHA256::BLOCK_SIZE]
+                .try_into()
+                .unwrap();
+            compress(&mut self.h, block);
+            offset += SHA256::BLOCK_SIZE;
+        }
+
+        let remainder = &data[offset..];
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+    }
+
+    /// pad the residual bytes with the `add_padding` tail (0x80, zero fill,
+    /// 64-bit big-endian bit length) and compress it, returning the digest
+    pub fn finalize(mut self) -> [u8; 32] {
+        const SIZE_BYTES: usize = 8;
+
+        let mut tail = self.buffer[..self.buffer_len].to_vec();
+        tail.push(SHA256::DELIMITER as u8);
+
+        let zero_fill = if tail.len() <= SHA256::BLOCK_SIZE - SIZE_BYTES {
+            SHA256::BLOCK_SIZE - SIZE_BYTES - tail.len()
+        } else {
+            SHA256::BLOCK_SIZE * 2 - SIZE_BYTES - tail.len()
+        };
+        tail.extend(std::iter::repeat(0u8).take(zero_fill));
+
+        let len_bits = (self.length as u64) * 8;
+        tail.extend_from_slice(&len_bits.to_be_bytes());
+
+        for block in tail.chunks(SHA256::BLOCK_SIZE) {
+            compress(&mut self.h, block.try_into().unwrap());
+        }
+
+        let mut digest = [0u8; 32];
+        for (i, word) in self.h.iter().enumerate() {
+            digest[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    /// snapshot the internal state after the whole 64-byte blocks
+    /// processed so far, as the serialized `h` words plus the byte count
+    /// processed. only valid at a block boundary — bytes buffered toward
+    /// a not-yet-complete block can't be captured, so calling this with
+    /// one in flight panics rather than silently discarding them.
+    pub fn midstate(&self) -> ([u8; 32], usize) {
+        assert_eq!(
+            self.buffer_len, 0,
+            "midstate() called with a partial block buffered; feed data in multiples of {} bytes first",
+            SHA256::BLOCK_SIZE
+        );
+
+        let mut h = [0u8; 32];
+        for (i, word) in self.h.iter().enumerate() {
+            h[i * 4..i * 4 + 4].copy_from_slice(&word.to_be_bytes());
+        }
+        (h, self.length)
+    }
+
+    /// resume an engine from a snapshot produced by `midstate`, as if
+    /// `processed_len` bytes had already been fed through `update`
+    pub fn from_midstate(h: [u8; 32], processed_len: usize) -> Self {
+        let mut state = [0u32; 8];
+        for (i, word) in state.iter_mut().enumerate() {
+            *word = u32::from_be_bytes(h[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+
+        HashEngine {
+            h: state,
+            buffer: [0; SHA256::BLOCK_SIZE],
+            buffer_len: 0,
+            length: processed_len,
+        }
+    }
+}
+
+/// SHA256d: SHA-256 of the SHA-256 of the message, the double-hash
+/// construction Bitcoin relies on
+pub fn sha256d(message: &[u8]) -> [u8; 32] {
+    let mut engine = HashEngine::new();
+    engine.update(message);
+    let first = engine.finalize();
+
+    let mut engine = HashEngine::new();
+    engine.update(&first);
+    engine.finalize()
+}
+
+/// render digest bytes as a zero-padded lowercase hex string, so bytes
+/// below 0x10 don't silently shorten the output
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// compress a single 64-byte block straight off the wire: the 16
+/// big-endian words are read directly from `block`, and the rest of the
+/// schedule is expanded on the fly into a rolling 16-word ring buffer
+/// instead of a full 64-word array, so `HashEngine` can drive large
+/// streaming inputs without a per-block heap allocation
+/// https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=24
+fn compress(state: &mut [u32; 8], block: &[u8; SHA256::BLOCK_SIZE]) {
+    let hasher = SHA256::new();
+    let mut w = [0u32; 16];
+
+    let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h) = (
+        state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7],
+    );
+
+    for t in 0..64 {
+        if t < 16 {
+            w[t] = u32::from_be_bytes(block[t * 4..t * 4 + 4].try_into().unwrap());
+        } else {
+            w[t & 15] = hasher
+                .sigma1(w[(t - 2) & 15])
+                .wrapping_add(w[(t - 7) & 15])
+                .wrapping_add(hasher.sigma0(w[(t - 15) & 15]))
+                .wrapping_add(w[(t - 16) & 15]);
+        }
+
+        let t1 = h
+            .wrapping_add(hasher.SIGMA1(e))
+            .wrapping_add(hasher.ch(e, f, g))
+            .wrapping_add(SHA256::K[t])
+            .wrapping_add(w[t & 15]);
+
+        let t2 = hasher.SIGMA0(a).wrapping_add(hasher.maj(a, b, c));
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_padding() {
+        {
+            let hasher = SHA256::new();
+            let pdd = hasher.add_padding(vec![]);
+            assert_eq!(pdd.len(), 64);
+            assert_eq!(
+                pdd,
+                vec![
+                    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ]
+            );
+        }
+        {
+            let hasher = SHA256::new();
+            let pdd = hasher.add_padding(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+            assert_eq!(pdd.len(), 64);
+            assert_eq!(
+                pdd,
+                vec![
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x80, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_sha256_exec() {
+        let hasher = SHA256::new();
+
+        assert_eq!(
+            hasher.exec(String::from("hello")),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(
+            hasher.exec(String::from("hello world")),
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+        assert_eq!(
+            hasher.exec(String::from("あいうえお")),
+            "fdb481ea956fdb654afcc327cff9b626966b2abdabc3f3e6dbcb1667a888ed9a"
+        );
+    }
+
+    #[test]
+    fn test_hash_engine_matches_exec() {
+        let hasher = SHA256::new();
+
+        let mut engine = HashEngine::new();
+        engine.update(b"hello world");
+        let digest = engine
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+
+        assert_eq!(digest, hasher.exec(String::from("hello world")));
+    }
+
+    #[test]
+    fn test_hash_engine_fed_in_pieces() {
+        let mut whole = HashEngine::new();
+        whole.update(b"hello world");
+
+        let mut piecemeal = HashEngine::new();
+        piecemeal.update(b"hello");
+        piecemeal.update(b" world");
+
+        assert_eq!(whole.finalize(), piecemeal.finalize());
+    }
+
+    #[test]
+    fn test_exec_keeps_leading_zero_nibbles() {
+        // "2" hashes to a digest with a word whose top nibble is zero
+        // (019c07d8); a hex formatter that drops leading zero nibbles
+        // would shorten it
+        let hasher = SHA256::new();
+        assert_eq!(
+            hasher.exec(String::from("2")),
+            "d4735e3a265e16eee03f59718b9b5d03019c07d8b6c51f90da3a666eec13ab35"
+        );
+    }
+
+    #[test]
+    fn test_exec_bytes_on_binary_input() {
+        let hasher = SHA256::new();
+        assert_eq!(
+            hasher.exec_bytes(&[0xff, 0x00, 0x01, 0x02]),
+            [
+                0x0c, 0x25, 0x2d, 0x84, 0x4a, 0x81, 0x5f, 0x83, 0xc5, 0x1e, 0x1c, 0x7d, 0xee, 0xe7,
+                0x3e, 0x3a, 0x71, 0x12, 0x04, 0x87, 0xd7, 0xf6, 0xdf, 0x63, 0x64, 0x64, 0x5b, 0x71,
+                0x1a, 0x8a, 0x33, 0xa7,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exec_bytes_spans_multiple_blocks() {
+        let hasher = SHA256::new();
+        let data: Vec<u8> = (0..256u16).map(|b| b as u8).collect::<Vec<u8>>().repeat(2);
+
+        assert_eq!(
+            hasher.exec_bytes(&data),
+            hex_to_bytes("110009dcee21620b166f3abfecb5eff7a873be729d1c2d53822e7acc5f34eb9b")
+        );
+    }
+
+    #[test]
+    fn test_midstate_resumes_a_forked_hash() {
+        let hasher = SHA256::new();
+
+        let prefix = [0x42u8; SHA256::BLOCK_SIZE * 2];
+        let mut prefix_engine = HashEngine::new();
+        prefix_engine.update(&prefix);
+        let (h, processed_len) = prefix_engine.midstate();
+        assert_eq!(processed_len, prefix.len());
+
+        let mut forked_a = HashEngine::from_midstate(h, processed_len);
+        forked_a.update(b"tail a");
+        let mut forked_b = HashEngine::from_midstate(h, processed_len);
+        forked_b.update(b"tail b");
+
+        let mut whole_a = HashEngine::new();
+        whole_a.update(&prefix);
+        whole_a.update(b"tail a");
+
+        assert_eq!(forked_a.finalize(), whole_a.finalize());
+        assert_ne!(forked_a.finalize(), forked_b.finalize());
+        assert_ne!(forked_a.finalize(), hasher.exec_bytes(&prefix));
+    }
+
+    #[test]
+    #[should_panic(expected = "midstate() called with a partial block buffered")]
+    fn test_midstate_rejects_a_partial_block() {
+        let mut engine = HashEngine::new();
+        engine.update(&[0x42u8; SHA256::BLOCK_SIZE + 1]);
+
+        engine.midstate();
+    }
+
+    #[test]
+    fn test_sha256d_hashes_twice() {
+        assert_eq!(
+            sha256d(b"hello"),
+            hex_to_bytes("9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50")
+        );
+        assert_eq!(
+            sha256d(b""),
+            hex_to_bytes("5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456")
+        );
+    }
+
+    fn hex_to_bytes(s: &str) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for i in 0..32 {
+            bytes[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        bytes
+    }
+}
@@ -0,0 +1,328 @@
+//! The SHA-512 hash algorithm, and its truncated sibling SHA-384.
+//! spec: https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf
+//! 6.4 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=25
+
+const BLOCK_SIZE: usize = 128;
+const DELIMITER: u8 = 0x80;
+
+/// first 64 bits of the fractional parts of the cube roots of the first
+/// 80 primes
+/// https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=15
+const K: [u64; 80] = [
+    0x428a2f98d728ae22,
+    0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f,
+    0xe9b5dba58189dbbc,
+    0x3956c25bf348b538,
+    0x59f111f1b605d019,
+    0x923f82a4af194f9b,
+    0xab1c5ed5da6d8118,
+    0xd807aa98a3030242,
+    0x12835b0145706fbe,
+    0x243185be4ee4b28c,
+    0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f,
+    0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235,
+    0xc19bf174cf692694,
+    0xe49b69c19ef14ad2,
+    0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5,
+    0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275,
+    0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4,
+    0x76f988da831153b5,
+    0x983e5152ee66dfab,
+    0xa831c66d2db43210,
+    0xb00327c898fb213f,
+    0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2,
+    0xd5a79147930aa725,
+    0x06ca6351e003826f,
+    0x142929670a0e6e70,
+    0x27b70a8546d22ffc,
+    0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df,
+    0x650a73548baf63de,
+    0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6,
+    0x92722c851482353b,
+    0xa2bfe8a14cf10364,
+    0xa81a664bbc423001,
+    0xc24b8b70d0f89791,
+    0xc76c51a30654be30,
+    0xd192e819d6ef5218,
+    0xd69906245565a910,
+    0xf40e35855771202a,
+    0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8,
+    0x1e376c085141ab53,
+    0x2748774cdf8eeb99,
+    0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63,
+    0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc,
+    0x78a5636f43172f60,
+    0x84c87814a1f0ab72,
+    0x8cc702081a6439ec,
+    0x90befffa23631e28,
+    0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915,
+    0xc67178f2e372532b,
+    0xca273eceea26619c,
+    0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e,
+    0xf57d4f7fee6ed178,
+    0x06f067aa72176fba,
+    0x0a637dc5a2c898a6,
+    0x113f9804bef90dae,
+    0x1b710b35131c471b,
+    0x28db77f523047d84,
+    0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6,
+    0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec,
+    0x6c44198c4a475817,
+];
+
+/// run the SHA-512/SHA-384 compression function (all 80 rounds) over a
+/// single 1024-bit block, returning the advanced state
+/// https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=25
+fn compress(state: [u64; 8], block: [u64; 16]) -> [u64; 8] {
+    let w = {
+        let mut w = [0; 80];
+
+        for t in 0..16 {
+            w[t] = block[t];
+        }
+
+        for t in 16..80 {
+            w[t] = sigma1(w[t - 2])
+                .wrapping_add(w[t - 7])
+                .wrapping_add(sigma0(w[t - 15]))
+                .wrapping_add(w[t - 16]);
+        }
+
+        w
+    };
+
+    let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h) = (
+        state[0], state[1], state[2], state[3], state[4], state[5], state[6], state[7],
+    );
+
+    for t in 0..80 {
+        let t1 = h
+            .wrapping_add(sigma_1(e))
+            .wrapping_add(ch(e, f, g))
+            .wrapping_add(K[t])
+            .wrapping_add(w[t]);
+
+        let t2 = sigma_0(a).wrapping_add(maj(a, b, c));
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+    }
+
+    [
+        a.wrapping_add(state[0]),
+        b.wrapping_add(state[1]),
+        c.wrapping_add(state[2]),
+        d.wrapping_add(state[3]),
+        e.wrapping_add(state[4]),
+        f.wrapping_add(state[5]),
+        g.wrapping_add(state[6]),
+        h.wrapping_add(state[7]),
+    ]
+}
+
+/// pad the message and run it through `compress`, starting from `h`,
+/// returning the final state words
+fn hash_with_state(message: &[u8], h: [u64; 8]) -> [u64; 8] {
+    let padded = add_padding(message);
+    let mut state = h;
+
+    for block in padded.chunks(BLOCK_SIZE) {
+        state = compress(state, bytes_to_block(block));
+    }
+
+    state
+}
+
+/// pre-process: pad the message to a multiple of 128 bytes, reserving
+/// the trailing 16 bytes for a 128-bit big-endian bit length
+/// https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=17
+fn add_padding(message: &[u8]) -> Vec<u8> {
+    const SIZE_BYTES: usize = 16;
+
+    let len = message.len();
+
+    let mut padded = message.to_vec();
+    padded.push(DELIMITER);
+
+    let zero_fill = if len % BLOCK_SIZE < BLOCK_SIZE - SIZE_BYTES {
+        BLOCK_SIZE - SIZE_BYTES - 1 - len % BLOCK_SIZE
+    } else {
+        BLOCK_SIZE * 2 - SIZE_BYTES - 1 - len % BLOCK_SIZE
+    };
+    padded.extend(std::iter::repeat(0u8).take(zero_fill));
+
+    // the message bit length as a full 128-bit big-endian integer
+    let len_bits = (len as u128) * 8;
+    padded.extend_from_slice(&len_bits.to_be_bytes());
+
+    padded
+}
+
+/// read 16 big-endian words out of a 128-byte block
+fn bytes_to_block(bytes: &[u8]) -> [u64; 16] {
+    let mut block = [0u64; 16];
+    for (i, word) in bytes.chunks(8).enumerate() {
+        block[i] = u64::from_be_bytes(word.try_into().unwrap());
+    }
+    block
+}
+
+/// render digest bytes as a zero-padded lowercase hex string
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// 4.2 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn ch(x: u64, y: u64, z: u64) -> u64 {
+    (x & y) ^ (!x & z)
+}
+/// 4.3 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn maj(x: u64, y: u64, z: u64) -> u64 {
+    (x & y) ^ (x & z) ^ (y & z)
+}
+
+/// 4.10 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn sigma_0(x: u64) -> u64 {
+    x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
+}
+/// 4.11 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn sigma_1(x: u64) -> u64 {
+    x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
+}
+/// 4.12 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn sigma0(x: u64) -> u64 {
+    x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
+}
+/// 4.13 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=14
+fn sigma1(x: u64) -> u64 {
+    x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
+}
+
+#[derive(Copy, Clone)]
+pub struct SHA512;
+impl SHA512 {
+    /// 8bytes after the decimal point of the square root of 8 prime numbers from smallest to largest
+    /// 5.3.4 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=18
+    const H: [u64; 8] = [
+        0x6a09e667f3bcc908,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    ];
+
+    pub fn new() -> Self {
+        SHA512
+    }
+
+    pub fn exec(self, message: String) -> String {
+        hex(&self.exec_bytes(message.as_bytes()))
+    }
+
+    /// hash arbitrary binary input and return the raw 64-byte digest
+    pub fn exec_bytes(self, message: &[u8]) -> [u8; 64] {
+        let state = hash_with_state(message, SHA512::H);
+
+        let mut digest = [0u8; 64];
+        for (i, word) in state.iter().enumerate() {
+            digest[i * 8..i * 8 + 8].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct SHA384;
+impl SHA384 {
+    /// 5.3.4 https://csrc.nist.gov/csrc/media/publications/fips/180/2/archive/2002-08-01/documents/fips180-2.pdf#page=18
+    const H: [u64; 8] = [
+        0xcbbb9d5dc1059ed8,
+        0x629a292a367cd507,
+        0x9159015a3070dd17,
+        0x152fecd8f70e5939,
+        0x67332667ffc00b31,
+        0x8eb44a8768581511,
+        0xdb0c2e0d64f98fa7,
+        0x47b5481dbefa4fa4,
+    ];
+
+    pub fn new() -> Self {
+        SHA384
+    }
+
+    pub fn exec(self, message: String) -> String {
+        hex(&self.exec_bytes(message.as_bytes()))
+    }
+
+    /// hash arbitrary binary input and return the raw 48-byte digest
+    /// (SHA-384 is SHA-512 with a different initial state, truncated to
+    /// the first 6 of its 8 output words)
+    pub fn exec_bytes(self, message: &[u8]) -> [u8; 48] {
+        let state = hash_with_state(message, SHA384::H);
+
+        let mut digest = [0u8; 48];
+        for (i, word) in state.iter().take(6).enumerate() {
+            digest[i * 8..i * 8 + 8].copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha512_exec() {
+        let hasher = SHA512::new();
+
+        assert_eq!(
+            hasher.exec(String::from("abc")),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(
+            hasher.exec(String::from("")),
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+    }
+
+    #[test]
+    fn test_sha384_exec() {
+        let hasher = SHA384::new();
+
+        assert_eq!(
+            hasher.exec(String::from("abc")),
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+        );
+    }
+}